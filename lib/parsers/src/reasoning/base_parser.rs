@@ -0,0 +1,385 @@
+// SPDX-FileCopyrightText: Copyright (c) 2024-2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::streaming::{MarkerEvent, MarkerScanner};
+use super::{ParserResult, ReasoningParser};
+
+/// Which phase of a reasoning block the parser is currently in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum State {
+    /// No block has started (or the parser is between blocks); watching for any configured
+    /// start marker.
+    AwaitingStart,
+    /// Inside a block; committed to `end_token` for the remainder of it, ignoring every other
+    /// configured marker pair until it closes. `pair_index` identifies which configured pair
+    /// this is, so a token-id boundary check (if configured) knows which end id to watch for.
+    InBlock {
+        end_token: String,
+        pair_index: Option<usize>,
+    },
+    /// A block has just closed; watching for the next start marker, same as `AwaitingStart`
+    /// but resolved text here is always normal text regardless of `force_reasoning`.
+    AfterBlock,
+}
+
+/// Where a token-id boundary check found a marker, by index into `marker_pairs`.
+enum TokenBoundary {
+    Start(usize),
+    End,
+}
+
+/// A reasoning parser driven by one or more `(start_token, end_token)` marker pairs, e.g.
+/// `<think>`/`</think>`.
+///
+/// With `force_reasoning` set, text seen before the first start marker (or when no marker ever
+/// appears at all) is treated as reasoning rather than normal text — some models are expected to
+/// always begin with reasoning and may omit the opening tag. A closing marker that appears
+/// before any opening marker is not recognised as a boundary; it is passed through as part of
+/// that leading text.
+#[derive(Debug)]
+pub struct BasicReasoningParser {
+    marker_pairs: Vec<(String, String)>,
+    /// Parallel to `marker_pairs`: the `(start_id, end_id)` token ids for each pair, when the
+    /// marker is known to be a single special token. `None` means always fall back to string
+    /// matching (the default — most callers don't have a tokenizer handy).
+    marker_token_ids: Option<Vec<(u32, u32)>>,
+    force_reasoning: bool,
+    stream_reasoning: bool,
+    scanner: MarkerScanner,
+    state: State,
+    buffered_reasoning: String,
+    /// Whether any non-whitespace reasoning text has been seen since the current block started.
+    /// Lets a block that is nothing but whitespace (e.g. `<think>\n</think>`) resolve to no
+    /// reasoning at all, the same as an empty block, instead of leaking the whitespace through.
+    block_has_content: bool,
+}
+
+impl BasicReasoningParser {
+    pub fn new(
+        start_token: String,
+        end_token: String,
+        force_reasoning: bool,
+        stream_reasoning: bool,
+    ) -> Self {
+        Self::with_markers(vec![(start_token, end_token)], force_reasoning, stream_reasoning)
+    }
+
+    /// Build a parser that accepts any of several alternative marker-pair dialects, dispatching
+    /// on whichever opening marker is seen first. Once a start marker matches, the parser
+    /// commits to that pair's matching end marker for the rest of the block — a different
+    /// pair's start marker recurring inside the block is not treated specially.
+    pub fn with_markers(
+        marker_pairs: Vec<(String, String)>,
+        force_reasoning: bool,
+        stream_reasoning: bool,
+    ) -> Self {
+        Self {
+            marker_pairs,
+            marker_token_ids: None,
+            force_reasoning,
+            stream_reasoning,
+            scanner: MarkerScanner::new(),
+            state: State::AwaitingStart,
+            buffered_reasoning: String::new(),
+            block_has_content: false,
+        }
+    }
+
+    /// Configure this parser to detect block boundaries by scanning `token_ids` directly
+    /// instead of matching `start_token`/`end_token` as substrings — needed when a marker like
+    /// `</think>` is emitted as a single special token that never appears as literal text, or
+    /// when the marker's characters legitimately occur inside other content.
+    ///
+    /// `token_ids` must have one `(start_id, end_id)` entry per marker pair passed to
+    /// [`Self::with_markers`] (or to [`Self::new`], which has exactly one pair), in the same
+    /// order. Falls back to string matching whenever a streaming call's `token_ids` doesn't
+    /// identify a single new token (e.g. a one-shot call with the whole message at once).
+    pub fn with_marker_token_ids(mut self, token_ids: Vec<(u32, u32)>) -> Self {
+        debug_assert_eq!(
+            token_ids.len(),
+            self.marker_pairs.len(),
+            "one (start_id, end_id) per marker pair is required"
+        );
+        self.marker_token_ids = Some(token_ids);
+        self
+    }
+
+    fn reset(&mut self) {
+        self.scanner.take_remainder();
+        self.state = State::AwaitingStart;
+        self.buffered_reasoning.clear();
+        self.block_has_content = false;
+    }
+
+    fn process_chunk(&mut self, text: &str, token_ids: &[u32]) -> ParserResult {
+        if let Some(boundary) = self.token_boundary(token_ids) {
+            // The whole of `text` is this one special token's surface form: the boundary itself,
+            // with no surrounding content to resolve in this call.
+            let mut result = ParserResult::default();
+            self.apply_boundary(&mut result, boundary);
+            return result;
+        }
+
+        if self.marker_token_ids.is_some() && token_ids.len() == 1 {
+            // A single token that is not a configured marker id is content, even if its text
+            // contains a marker string; any partial marker held from an earlier call goes with it.
+            let mut pending = self.scanner.take_remainder();
+            pending.push_str(text);
+            let mut result = ParserResult::default();
+            self.resolve(&mut result, pending);
+            return result;
+        }
+
+        self.scanner.push(text);
+        let mut result = ParserResult::default();
+
+        loop {
+            let active_markers = self.active_markers();
+            let active_markers: Vec<&str> = active_markers.iter().map(String::as_str).collect();
+            match self.scanner.scan(&active_markers) {
+                MarkerEvent::Found {
+                    text_before,
+                    marker,
+                } => {
+                    self.resolve(&mut result, text_before);
+                    let boundary = match &self.state {
+                        State::InBlock { .. } => TokenBoundary::End,
+                        State::AwaitingStart | State::AfterBlock => {
+                            // `marker` always came from one of `marker_pairs`' own start
+                            // tokens via `active_markers`, so this lookup always succeeds.
+                            let pair_index = self
+                                .marker_pairs
+                                .iter()
+                                .position(|(start, _)| *start == marker)
+                                .expect("matched marker must belong to a configured pair");
+                            TokenBoundary::Start(pair_index)
+                        }
+                    };
+                    self.apply_boundary(&mut result, boundary);
+                }
+                MarkerEvent::Pending { text } => {
+                    self.resolve(&mut result, text);
+                    break;
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Scan `token_ids` for a configured marker id matching the current state, when this
+    /// parser was built with [`Self::with_marker_token_ids`]. Only decisive for the common
+    /// single-token-per-call streaming shape; any other shape (batched token_ids, or none
+    /// configured) defers to string matching.
+    fn token_boundary(&self, token_ids: &[u32]) -> Option<TokenBoundary> {
+        let ids = self.marker_token_ids.as_ref()?;
+        let [id] = token_ids else { return None };
+
+        match &self.state {
+            State::InBlock { pair_index, .. } => {
+                let idx = (*pair_index)?;
+                let (_, end_id) = ids.get(idx)?;
+                (*end_id == *id).then_some(TokenBoundary::End)
+            }
+            State::AwaitingStart | State::AfterBlock => ids
+                .iter()
+                .position(|(start_id, _)| *start_id == *id)
+                .map(TokenBoundary::Start),
+        }
+    }
+
+    fn apply_boundary(&mut self, result: &mut ParserResult, boundary: TokenBoundary) {
+        match boundary {
+            TokenBoundary::Start(idx) => {
+                self.state = State::InBlock {
+                    end_token: self.marker_pairs[idx].1.clone(),
+                    pair_index: Some(idx),
+                };
+                self.block_has_content = false;
+            }
+            TokenBoundary::End => {
+                self.flush_buffered_reasoning(result);
+                self.state = State::AfterBlock;
+            }
+        }
+    }
+
+    fn active_markers(&self) -> Vec<String> {
+        match &self.state {
+            State::InBlock { end_token, .. } => vec![end_token.clone()],
+            State::AwaitingStart | State::AfterBlock => self
+                .marker_pairs
+                .iter()
+                .map(|(start, _)| start.clone())
+                .collect(),
+        }
+    }
+
+    /// Route newly-resolved text to the right output field for the current state, honoring
+    /// `stream_reasoning` by holding reasoning text back until the block closes.
+    fn resolve(&mut self, result: &mut ParserResult, text: String) {
+        if text.is_empty() {
+            return;
+        }
+        match &self.state {
+            State::AfterBlock => result.normal_text.push_str(&text),
+            State::AwaitingStart if self.force_reasoning => {
+                if self.stream_reasoning {
+                    result.reasoning_text.push_str(&text);
+                } else {
+                    self.buffered_reasoning.push_str(&text);
+                }
+            }
+            State::AwaitingStart => result.normal_text.push_str(&text),
+            State::InBlock { .. } => {
+                if !self.block_has_content && text.trim().is_empty() {
+                    // Leading whitespace in an otherwise-empty block (e.g. `<think>\n</think>`)
+                    // is not meaningful reasoning; drop it rather than leaking it through.
+                    return;
+                }
+                self.block_has_content = true;
+                if self.stream_reasoning {
+                    result.reasoning_text.push_str(&text);
+                } else {
+                    self.buffered_reasoning.push_str(&text);
+                }
+            }
+        }
+    }
+
+    fn flush_buffered_reasoning(&mut self, result: &mut ParserResult) {
+        if !self.buffered_reasoning.is_empty() {
+            result
+                .reasoning_text
+                .push_str(&std::mem::take(&mut self.buffered_reasoning));
+        }
+    }
+}
+
+impl ReasoningParser for BasicReasoningParser {
+    fn detect_and_parse_reasoning(&mut self, text: &str, token_ids: &[u32]) -> ParserResult {
+        self.reset();
+        let mut result = self.parse_reasoning_streaming_incremental(text, token_ids);
+        let remainder = self.scanner.take_remainder();
+        self.resolve(&mut result, remainder);
+        self.flush_buffered_reasoning(&mut result);
+        result
+    }
+
+    fn parse_reasoning_streaming_incremental(
+        &mut self,
+        text: &str,
+        token_ids: &[u32],
+    ) -> ParserResult {
+        self.process_chunk(text, token_ids)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_no_markers_non_force_is_normal_text() {
+        let mut parser =
+            BasicReasoningParser::new("<think>".into(), "</think>".into(), false, true);
+        let result = parser.detect_and_parse_reasoning("just plain text", &[]);
+        assert_eq!(result.normal_text, "just plain text");
+        assert_eq!(result.reasoning_text, "");
+    }
+
+    #[test]
+    fn test_stream_reasoning_false_buffers_until_block_closes() {
+        let mut parser =
+            BasicReasoningParser::new("<think>".into(), "</think>".into(), false, false);
+
+        let r1 = parser.parse_reasoning_streaming_incremental("<think>partial", &[]);
+        assert_eq!(r1.reasoning_text, "");
+
+        let r2 = parser.parse_reasoning_streaming_incremental(" reasoning</think>done", &[]);
+        assert_eq!(r2.reasoning_text, "partial reasoning");
+        assert_eq!(r2.normal_text, "done");
+    }
+
+    #[test]
+    fn test_with_markers_dispatches_on_whichever_pair_opens() {
+        let mut parser = BasicReasoningParser::with_markers(
+            vec![
+                ("<think>".to_string(), "</think>".to_string()),
+                ("◁think▷".to_string(), "◁/think▷".to_string()),
+            ],
+            false,
+            true,
+        );
+
+        let result = parser.detect_and_parse_reasoning("<think>reasoning</think>answer", &[]);
+        assert_eq!(result.reasoning_text, "reasoning");
+        assert_eq!(result.normal_text, "answer");
+
+        let mut parser = BasicReasoningParser::with_markers(
+            vec![
+                ("<think>".to_string(), "</think>".to_string()),
+                ("◁think▷".to_string(), "◁/think▷".to_string()),
+            ],
+            false,
+            true,
+        );
+        let result = parser.detect_and_parse_reasoning("◁think▷reasoning◁/think▷answer", &[]);
+        assert_eq!(result.reasoning_text, "reasoning");
+        assert_eq!(result.normal_text, "answer");
+    }
+
+    #[test]
+    fn test_with_markers_commits_to_matched_pairs_end() {
+        // A different pair's start marker recurring inside the block must not be special-cased;
+        // the parser only watches for the end marker it committed to.
+        let mut parser = BasicReasoningParser::with_markers(
+            vec![
+                ("<think>".to_string(), "</think>".to_string()),
+                ("◁think▷".to_string(), "◁/think▷".to_string()),
+            ],
+            false,
+            true,
+        );
+        let result =
+            parser.detect_and_parse_reasoning("<think>contains ◁think▷ literally</think>done", &[]);
+        assert_eq!(result.reasoning_text, "contains ◁think▷ literally");
+        assert_eq!(result.normal_text, "done");
+    }
+
+    #[test]
+    fn test_token_ids_detect_boundaries_even_when_text_differs_from_marker() {
+        // `</think>` as a single special token (id 100) whose decoded text doesn't literally
+        // contain "</think>" — string matching alone would never close the block.
+        let mut parser =
+            BasicReasoningParser::new("<think>".into(), "</think>".into(), false, true)
+                .with_marker_token_ids(vec![(99, 100)]);
+
+        let r1 = parser.parse_reasoning_streaming_incremental("<opening-marker>", &[99]);
+        assert_eq!(r1.normal_text, "");
+        assert_eq!(r1.reasoning_text, "");
+
+        let r2 = parser.parse_reasoning_streaming_incremental("some reasoning", &[1]);
+        assert_eq!(r2.reasoning_text, "some reasoning");
+
+        let r3 = parser.parse_reasoning_streaming_incremental("<closing-marker-not-think>", &[100]);
+        assert_eq!(r3.reasoning_text, "");
+        assert_eq!(r3.normal_text, "");
+
+        let r4 = parser.parse_reasoning_streaming_incremental("answer", &[2]);
+        assert_eq!(r4.normal_text, "answer");
+    }
+
+    #[test]
+    fn test_token_ids_fall_back_to_string_matching_without_single_token_delta() {
+        let mut parser =
+            BasicReasoningParser::new("<think>".into(), "</think>".into(), false, true)
+                .with_marker_token_ids(vec![(99, 100)]);
+
+        // A one-shot call carries the whole message, not a single new token: falls back to
+        // string matching on the literal `<think>`/`</think>` markers.
+        let result = parser.detect_and_parse_reasoning("<think>reasoning</think>answer", &[]);
+        assert_eq!(result.reasoning_text, "reasoning");
+        assert_eq!(result.normal_text, "answer");
+    }
+}
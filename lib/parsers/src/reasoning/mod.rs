@@ -1,11 +1,12 @@
 // SPDX-FileCopyrightText: Copyright (c) 2024-2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
 // SPDX-License-Identifier: Apache-2.0
 use std::collections::HashMap;
-use std::sync::OnceLock;
+use std::sync::{OnceLock, RwLock};
 
 mod base_parser;
 mod gpt_oss_parser;
 mod granite_parser;
+mod streaming;
 
 // Re-export main types and functions for convenience
 pub use base_parser::BasicReasoningParser;
@@ -25,6 +26,7 @@ fn get_reasoning_parser_map() -> &'static HashMap<&'static str, ReasoningParserT
         map.insert("nemotron_deci", ReasoningParserType::NemotronDeci);
         map.insert("kimi", ReasoningParserType::Kimi);
         map.insert("kimi_k25", ReasoningParserType::KimiK25);
+        map.insert("kimi_multi", ReasoningParserType::KimiMulti);
         map.insert("step3", ReasoningParserType::Step3);
         map.insert("mistral", ReasoningParserType::Mistral);
         map.insert("granite", ReasoningParserType::Granite);
@@ -33,9 +35,42 @@ fn get_reasoning_parser_map() -> &'static HashMap<&'static str, ReasoningParserT
     })
 }
 
-/// Get all available reasoning parser names
-pub fn get_available_reasoning_parsers() -> Vec<&'static str> {
-    get_reasoning_parser_map().keys().copied().collect()
+/// A factory for a runtime-registered custom reasoning parser.
+pub type ReasoningParserFactory = Box<dyn Fn() -> Box<dyn ReasoningParser> + Send + Sync>;
+
+static CUSTOM_REASONING_PARSERS: OnceLock<RwLock<HashMap<String, ReasoningParserFactory>>> =
+    OnceLock::new();
+
+fn get_custom_reasoning_parsers() -> &'static RwLock<HashMap<String, ReasoningParserFactory>> {
+    CUSTOM_REASONING_PARSERS.get_or_init(|| RwLock::new(HashMap::new()))
+}
+
+/// Register a custom reasoning parser under `name`, layered over the built-in defaults so
+/// downstream users can add support for a new model's delimiters without editing this crate.
+/// `name` is matched case-insensitively, same as the built-in names. Registering again under an
+/// existing name replaces the previous factory, including a built-in one.
+pub fn register_reasoning_parser(name: &str, factory: ReasoningParserFactory) {
+    get_custom_reasoning_parsers()
+        .write()
+        .expect("custom reasoning parser registry lock poisoned")
+        .insert(name.to_lowercase(), factory);
+}
+
+/// Get all available reasoning parser names: built-in defaults plus anything registered at
+/// runtime via [`register_reasoning_parser`].
+pub fn get_available_reasoning_parsers() -> Vec<String> {
+    let mut names: Vec<String> = get_reasoning_parser_map()
+        .keys()
+        .map(|name| name.to_string())
+        .collect();
+    names.extend(
+        get_custom_reasoning_parsers()
+            .read()
+            .expect("custom reasoning parser registry lock poisoned")
+            .keys()
+            .cloned(),
+    );
+    names
 }
 
 #[derive(Debug, Clone, Default)]
@@ -94,6 +129,9 @@ pub enum ReasoningParserType {
     KimiK25,
     Mistral,
     Granite,
+    /// Accepts both the Kimi and Kimi K2.5 `<think>`-style delimiters, for deployments where a
+    /// router sits in front of several checkpoints that don't all emit the same marker style.
+    KimiMulti,
 }
 
 #[derive(std::fmt::Debug)]
@@ -154,6 +192,16 @@ impl ReasoningParserType {
                     true,
                 )),
             },
+            ReasoningParserType::KimiMulti => ReasoningParserWrapper {
+                parser: Box::new(BasicReasoningParser::with_markers(
+                    vec![
+                        ("◁think▷".into(), "◁/think▷".into()),
+                        ("<think>".into(), "</think>".into()),
+                    ],
+                    false,
+                    true,
+                )),
+            },
             ReasoningParserType::Mistral => ReasoningParserWrapper {
                 parser: Box::new(BasicReasoningParser::new(
                     "[THINK]".into(),
@@ -189,9 +237,19 @@ impl ReasoningParserType {
     pub fn get_reasoning_parser_from_name(name: &str) -> ReasoningParserWrapper {
         tracing::debug!("Selected reasoning parser: {}", name);
 
-        let parser_map = get_reasoning_parser_map();
         let normalized_name = name.to_lowercase();
 
+        if let Some(factory) = get_custom_reasoning_parsers()
+            .read()
+            .expect("custom reasoning parser registry lock poisoned")
+            .get(normalized_name.as_str())
+        {
+            return ReasoningParserWrapper {
+                parser: factory(),
+            };
+        }
+
+        let parser_map = get_reasoning_parser_map();
         match parser_map.get(normalized_name.as_str()) {
             Some(parser_type) => parser_type.get_reasoning_parser(),
             None => {
@@ -222,16 +280,60 @@ mod tests {
             "nemotron_deci",
             "kimi",
             "kimi_k25",
+            "kimi_multi",
             "step3",
             "mistral",
             "granite",
             "nemotron_nano",
         ];
         for parser in available_parsers {
-            assert!(parsers.contains(&parser));
+            assert!(parsers.iter().any(|p| p == parser));
         }
     }
 
+    #[test]
+    fn test_register_reasoning_parser_is_listed_and_resolvable() {
+        register_reasoning_parser(
+            "test_custom_parser",
+            Box::new(|| {
+                Box::new(BasicReasoningParser::new(
+                    "[[R]]".into(),
+                    "[[/R]]".into(),
+                    false,
+                    true,
+                ))
+            }),
+        );
+
+        let parsers = get_available_reasoning_parsers();
+        assert!(parsers.iter().any(|p| p == "test_custom_parser"));
+
+        let mut parser = ReasoningParserType::get_reasoning_parser_from_name("TEST_CUSTOM_PARSER");
+        let result = parser.detect_and_parse_reasoning("[[R]]thinking[[/R]]answer", &[]);
+        assert_eq!(result.reasoning_text, "thinking");
+        assert_eq!(result.normal_text, "answer");
+    }
+
+    #[test]
+    fn test_register_reasoning_parser_overrides_builtin_name() {
+        register_reasoning_parser(
+            "basic",
+            Box::new(|| {
+                Box::new(BasicReasoningParser::new(
+                    "{{think}}".into(),
+                    "{{/think}}".into(),
+                    false,
+                    true,
+                ))
+            }),
+        );
+
+        let mut parser = ReasoningParserType::get_reasoning_parser_from_name("basic");
+        let result = parser.detect_and_parse_reasoning("{{think}}override{{/think}}done", &[]);
+        assert_eq!(result.reasoning_text, "override");
+        assert_eq!(result.normal_text, "done");
+    }
+
     #[test]
     fn test_kimi_k25_parser_is_force_reasoning() {
         // KimiK25 uses force_reasoning=true: output without <think> tags is still treated as reasoning
@@ -350,6 +452,19 @@ mod tests {
         assert_eq!(result.normal_text, "answer");
     }
 
+    #[test]
+    fn test_kimi_multi_accepts_either_delimiter_style() {
+        let mut parser = ReasoningParserType::get_reasoning_parser_from_name("kimi_multi");
+        let result = parser.detect_and_parse_reasoning("◁think▷thinking◁/think▷answer", &[]);
+        assert_eq!(result.reasoning_text, "thinking");
+        assert_eq!(result.normal_text, "answer");
+
+        let mut parser = ReasoningParserType::get_reasoning_parser_from_name("kimi_multi");
+        let result = parser.detect_and_parse_reasoning("<think>thinking</think>answer", &[]);
+        assert_eq!(result.reasoning_text, "thinking");
+        assert_eq!(result.normal_text, "answer");
+    }
+
     #[test]
     fn test_kimi_vs_kimi_k25_different_tags() {
         // Kimi (original) uses ◁think▷/◁/think▷, KimiK25 uses <think>/</think>
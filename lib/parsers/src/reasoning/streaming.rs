@@ -0,0 +1,165 @@
+// SPDX-FileCopyrightText: Copyright (c) 2024-2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! A shared streaming engine for marker-delimited parsers (reasoning blocks, tool calls, ...).
+//!
+//! Modeled on parser-combinator streaming semantics (as in the `combine` crate, which
+//! classifies every parse attempt as a committed success, a definite failure, or "needs more
+//! input"): every [`MarkerScanner::scan`] call either finds a complete marker, or returns the
+//! text that is safe to resolve, holding back any tail of the buffer that is a prefix of a
+//! marker and therefore needs more input before a decision can be made. Partial markers are
+//! never emitted and never lost.
+
+/// The outcome of one [`MarkerScanner::scan`] call.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MarkerEvent {
+    /// A complete marker was found. `text_before` is resolved text that preceded it (under
+    /// whatever mode the caller currently considers active); `marker` is the matched marker
+    /// string, already consumed from the buffer, so the caller can decide which mode to
+    /// transition into and call `scan` again over the remainder.
+    Found { text_before: String, marker: String },
+    /// No complete marker was found. `text` is everything in the buffer before its longest
+    /// suffix that is a strict, non-empty prefix of some active marker (the whole buffer if no
+    /// such suffix exists), and is therefore safe to resolve under the current mode. That
+    /// suffix stays buffered so a later chunk can complete it.
+    Pending { text: String },
+}
+
+/// Buffers streamed text and classifies it against a set of marker strings, holding back any
+/// tail that might still turn into a marker once more input arrives.
+#[derive(Debug, Default)]
+pub struct MarkerScanner {
+    buffer: String,
+}
+
+impl MarkerScanner {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append newly streamed text to the internal buffer.
+    pub fn push(&mut self, text: &str) {
+        self.buffer.push_str(text);
+    }
+
+    /// Classify the buffer against `markers`. Call repeatedly (after handling `Found`) until
+    /// `Pending` is returned, so multiple markers in a single chunk are all discovered.
+    pub fn scan(&mut self, markers: &[&str]) -> MarkerEvent {
+        let earliest = markers
+            .iter()
+            .filter_map(|m| self.buffer.find(m).map(|pos| (pos, *m)))
+            .min_by_key(|(pos, _)| *pos);
+
+        if let Some((pos, marker)) = earliest {
+            let text_before: String = self.buffer.drain(..pos).collect();
+            self.buffer.drain(..marker.len());
+            return MarkerEvent::Found {
+                text_before,
+                marker: marker.to_string(),
+            };
+        }
+
+        let cut = self.longest_marker_prefix_cut(markers);
+        let text: String = self.buffer.drain(..cut).collect();
+        MarkerEvent::Pending { text }
+    }
+
+    /// Whether the buffer is fully drained (no pending partial marker).
+    pub fn is_empty(&self) -> bool {
+        self.buffer.is_empty()
+    }
+
+    /// Drain and return whatever remains buffered, e.g. to flush a non-streaming input where
+    /// no more chunks are coming and a partial marker should just be treated as plain text.
+    pub fn take_remainder(&mut self) -> String {
+        std::mem::take(&mut self.buffer)
+    }
+
+    /// The byte offset of the longest suffix of the buffer that is a strict, non-empty prefix
+    /// of one of `markers` (recomputed fresh every call, since it must be re-derived after
+    /// every state transition, not just appended to).
+    fn longest_marker_prefix_cut(&self, markers: &[&str]) -> usize {
+        for (idx, _) in self.buffer.char_indices() {
+            let suffix = &self.buffer[idx..];
+            if markers
+                .iter()
+                .any(|m| !suffix.is_empty() && m.len() > suffix.len() && m.starts_with(suffix))
+            {
+                return idx;
+            }
+        }
+        self.buffer.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scan_finds_complete_marker() {
+        let mut scanner = MarkerScanner::new();
+        scanner.push("hello<think>world");
+        match scanner.scan(&["<think>", "</think>"]) {
+            MarkerEvent::Found {
+                text_before,
+                marker,
+            } => {
+                assert_eq!(text_before, "hello");
+                assert_eq!(marker, "<think>");
+            }
+            other => panic!("expected Found, got {other:?}"),
+        }
+        match scanner.scan(&["<think>", "</think>"]) {
+            MarkerEvent::Pending { text } => assert_eq!(text, "world"),
+            other => panic!("expected Pending, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_scan_holds_back_partial_marker() {
+        let mut scanner = MarkerScanner::new();
+        scanner.push("text<thi");
+        match scanner.scan(&["<think>"]) {
+            MarkerEvent::Pending { text } => assert_eq!(text, "text"),
+            other => panic!("expected Pending, got {other:?}"),
+        }
+        assert!(!scanner.is_empty());
+
+        scanner.push("nk>more");
+        match scanner.scan(&["<think>"]) {
+            MarkerEvent::Found { text_before, .. } => assert_eq!(text_before, ""),
+            other => panic!("expected Found, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_scan_no_marker_emits_everything() {
+        let mut scanner = MarkerScanner::new();
+        scanner.push("plain text");
+        match scanner.scan(&["<think>"]) {
+            MarkerEvent::Pending { text } => assert_eq!(text, "plain text"),
+            other => panic!("expected Pending, got {other:?}"),
+        }
+        assert!(scanner.is_empty());
+    }
+
+    #[test]
+    fn test_scan_earliest_of_multiple_active_markers() {
+        let mut scanner = MarkerScanner::new();
+        scanner.push("abc</think>def<think>ghi");
+        match scanner.scan(&["<think>", "</think>"]) {
+            MarkerEvent::Found { marker, .. } => assert_eq!(marker, "</think>"),
+            other => panic!("expected Found, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_take_remainder_flushes_partial_marker() {
+        let mut scanner = MarkerScanner::new();
+        scanner.push("trailing<thi");
+        scanner.scan(&["<think>"]);
+        assert_eq!(scanner.take_remainder(), "<thi");
+        assert!(scanner.is_empty());
+    }
+}
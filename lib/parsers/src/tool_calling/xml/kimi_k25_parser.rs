@@ -11,6 +11,7 @@ use regex::Regex;
 use super::super::ToolDefinition;
 use super::super::config::KimiK25ParserConfig;
 use super::response::{CalledFunction, ToolCallResponse, ToolCallType};
+use super::stream::ToolCallDelta;
 
 static TOOL_CALL_REGEX: OnceLock<Regex> = OnceLock::new();
 static ID_REGEX: OnceLock<Regex> = OnceLock::new();
@@ -191,17 +192,21 @@ fn parse_section_block(
             }
         }
 
-        // Validate JSON arguments
+        // Validate JSON arguments, falling back to a tolerant repair pass for the
+        // almost-valid JSON models frequently emit before giving up and storing the raw string.
         let arguments_json = match serde_json::from_str::<serde_json::Value>(arguments_raw) {
             Ok(val) => serde_json::to_string(&val)?,
-            Err(e) => {
-                tracing::warn!(
-                    "Failed to parse JSON arguments for tool '{}': {}. Using raw string.",
-                    function_name,
-                    e,
-                );
-                arguments_raw.to_string()
-            }
+            Err(e) => match repair_json_arguments(arguments_raw) {
+                Some(val) => serde_json::to_string(&val)?,
+                None => {
+                    tracing::warn!(
+                        "Failed to parse JSON arguments for tool '{}': {}. Using raw string.",
+                        function_name,
+                        e,
+                    );
+                    arguments_raw.to_string()
+                }
+            },
         };
 
         // Preserve the original function_id (e.g., "functions.bash:0") as the tool call ID.
@@ -224,6 +229,519 @@ fn parse_section_block(
     Ok(results)
 }
 
+/// Recover a usable JSON object from almost-valid or truncated tool-call arguments.
+///
+/// Models frequently emit arguments that are *almost* JSON: trailing commas, unquoted keys,
+/// single-quoted strings, Python-style `True`/`False`/`None`, or a stream cut off mid-object.
+/// This strips trailing commas, converts single-quoted strings and Python literals, and, for
+/// truncated input, closes any still-open structures by walking a brace/bracket/in-string
+/// stack. Unquoted keys are not repaired. Returns `None` (keeping today's raw-string fallback)
+/// unless the repaired text parses and is a JSON object.
+fn repair_json_arguments(raw: &str) -> Option<serde_json::Value> {
+    // Quotes first, so the literal pass sees single-quoted strings as string literals too.
+    let quoted = normalize_single_quotes(raw);
+    let normalized = normalize_python_literals(&quoted);
+    let closed = close_unterminated_structures(&normalized);
+    let trimmed = strip_trailing_commas(&closed);
+
+    match serde_json::from_str::<serde_json::Value>(&trimmed) {
+        Ok(val) if val.is_object() => Some(val),
+        _ => None,
+    }
+}
+
+/// Normalize bare Python-style `True`/`False`/`None` literals to `true`/`false`/`null`,
+/// skipping occurrences inside string literals.
+fn normalize_python_literals(raw: &str) -> String {
+    const LITERALS: [(&str, &str); 3] = [("True", "true"), ("False", "false"), ("None", "null")];
+
+    let mut out = String::with_capacity(raw.len());
+    let mut in_string = false;
+    let mut escaped = false;
+    let chars: Vec<char> = raw.chars().collect();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+
+        if in_string {
+            out.push(c);
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == '"' {
+                in_string = false;
+            }
+            i += 1;
+            continue;
+        }
+
+        if c == '"' {
+            in_string = true;
+            out.push(c);
+            i += 1;
+            continue;
+        }
+
+        let remaining: String = chars[i..].iter().collect();
+        let matched = LITERALS.iter().find(|(literal, _)| {
+            remaining.starts_with(literal)
+                && (i == 0 || !chars[i - 1].is_alphanumeric())
+                && chars
+                    .get(i + literal.len())
+                    .map_or(true, |c| !c.is_alphanumeric())
+        });
+
+        match matched {
+            Some((literal, replacement)) => {
+                out.push_str(replacement);
+                i += literal.len();
+            }
+            None => {
+                out.push(c);
+                i += 1;
+            }
+        }
+    }
+
+    out
+}
+
+/// Convert single-quoted strings/keys to double-quoted, escaping any double quotes inside the
+/// single-quoted span. Single quotes inside double-quoted strings, and an unclosed single quote,
+/// are left as they are.
+fn normalize_single_quotes(raw: &str) -> String {
+    if !raw.contains('\'') {
+        return raw.to_string();
+    }
+
+    let mut out = String::with_capacity(raw.len());
+    let mut in_double_string = false;
+    let mut escaped = false;
+    let mut chars = raw.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if in_double_string {
+            out.push(c);
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == '"' {
+                in_double_string = false;
+            }
+            continue;
+        }
+
+        if c == '"' {
+            in_double_string = true;
+            out.push(c);
+            continue;
+        }
+
+        if c == '\'' {
+            // Collect up to the closing single quote and re-emit as a double-quoted string,
+            // escaping any embedded double quotes.
+            let mut content = String::new();
+            let mut closed = false;
+            for inner in chars.by_ref() {
+                if inner == '\'' {
+                    closed = true;
+                    break;
+                }
+                if inner == '"' {
+                    content.push('\\');
+                }
+                content.push(inner);
+            }
+            if closed {
+                out.push('"');
+                out.push_str(&content);
+                out.push('"');
+            } else {
+                // No matching close quote found; leave the original text untouched.
+                out.push('\'');
+                out.push_str(&content);
+            }
+            continue;
+        }
+
+        out.push(c);
+    }
+
+    out
+}
+
+/// Strip a trailing comma that directly precedes a closing `}` or `]`.
+fn strip_trailing_commas(raw: &str) -> String {
+    let mut out = String::with_capacity(raw.len());
+    let mut in_string = false;
+    let mut escaped = false;
+    let chars: Vec<char> = raw.chars().collect();
+
+    for (i, &c) in chars.iter().enumerate() {
+        if in_string {
+            out.push(c);
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == '"' {
+                in_string = false;
+            }
+            continue;
+        }
+
+        if c == '"' {
+            in_string = true;
+            out.push(c);
+            continue;
+        }
+
+        if c == ',' {
+            let next_significant = chars[i + 1..].iter().find(|c| !c.is_whitespace());
+            if matches!(next_significant, Some('}') | Some(']')) {
+                continue;
+            }
+        }
+
+        out.push(c);
+    }
+
+    out
+}
+
+/// For truncated input, close any still-open braces/brackets/strings by walking a stack of
+/// open delimiters and appending the matching closers (and a closing quote if cut mid-string).
+fn close_unterminated_structures(raw: &str) -> String {
+    let mut stack = Vec::new();
+    let mut in_string = false;
+    let mut escaped = false;
+
+    for c in raw.chars() {
+        if in_string {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == '"' {
+                in_string = false;
+            }
+            continue;
+        }
+
+        match c {
+            '"' => in_string = true,
+            '{' => stack.push('}'),
+            '[' => stack.push(']'),
+            '}' | ']' => {
+                if stack.last() == Some(&c) {
+                    stack.pop();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut out = raw.to_string();
+    if in_string {
+        out.push('"');
+    }
+    while let Some(closer) = stack.pop() {
+        out.push(closer);
+    }
+    out
+}
+
+/// Internal state of [`KimiK25StreamParser`] as it scans the incoming byte stream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StreamState {
+    /// Outside any tool calls section; waiting for `section_start`.
+    Text,
+    /// Inside a section, waiting for the next `call_start` (or `section_end`).
+    InSection,
+    /// Seen `call_start`, accumulating the `functions.name:idx` id up to `argument_begin`.
+    InCallId,
+    /// Seen `argument_begin`, streaming JSON argument bytes up to `call_end`.
+    InArgs,
+    /// The argument object has balanced and been emitted; watching for `call_end` before the
+    /// next call (or the section end), discarding any trailing bytes in between.
+    AwaitingCallEnd,
+}
+
+/// Stateful, incremental counterpart to [`try_tool_call_parse_kimi_k25`].
+///
+/// Frontends that stream SSE tokens can feed raw chunks to [`Self::push`] as they arrive and
+/// get back OpenAI-style `tool_calls` deltas (index, function name, `arguments`) without waiting
+/// for `<|tool_calls_section_end|>`. A call's arguments are emitted as one fragment once its JSON
+/// object balances (or, for non-JSON arguments, once `call_end` arrives); partial argument bytes
+/// are not emitted before that. Call [`Self::finish`] once the stream ends to flush any
+/// arguments bytes that were buffered but never closed.
+///
+/// Special tokens that straddle a chunk boundary (e.g. a chunk ending in `<|tool_call_be`) are
+/// held back in an internal carry-over buffer rather than emitted or dropped, so `push` never
+/// needs a complete token in a single call.
+#[derive(Debug)]
+pub struct KimiK25StreamParser {
+    config: KimiK25ParserConfig,
+    buffer: String,
+    state: StreamState,
+    next_index: usize,
+}
+
+impl KimiK25StreamParser {
+    pub fn new(config: KimiK25ParserConfig) -> Self {
+        Self {
+            config,
+            buffer: String::new(),
+            state: StreamState::Text,
+            next_index: 0,
+        }
+    }
+
+    /// Feed the next chunk of streamed text, returning any deltas it produced.
+    pub fn push(&mut self, chunk: &str) -> Vec<ToolCallDelta> {
+        self.buffer.push_str(chunk);
+
+        let mut deltas = Vec::new();
+        loop {
+            let progressed = match self.state {
+                StreamState::Text => self.advance_text(),
+                StreamState::InSection => self.advance_in_section(),
+                StreamState::InCallId => self.advance_in_call_id(&mut deltas),
+                StreamState::InArgs => self.advance_in_args(&mut deltas),
+                StreamState::AwaitingCallEnd => self.advance_awaiting_call_end(),
+            };
+            if !progressed {
+                break;
+            }
+        }
+        deltas
+    }
+
+    /// Flush any argument bytes buffered but not yet terminated by `call_end`.
+    ///
+    /// Returns a final delta carrying the remaining argument bytes, if any were pending. Since
+    /// no more input is coming, this is also the one place a stream genuinely ends up with a
+    /// truncated argument object, so it runs the same [`repair_json_arguments`] pass used for
+    /// almost-valid JSON, closing any still-open braces/strings; the raw text is kept only if
+    /// repair can't recover a JSON object from it. Call this once after the stream has ended;
+    /// the parser is reset afterwards.
+    pub fn finish(&mut self) -> Option<ToolCallDelta> {
+        let delta = if self.state == StreamState::InArgs && !self.buffer.is_empty() {
+            let raw = std::mem::take(&mut self.buffer);
+            let arguments = repair_json_arguments(&raw)
+                .and_then(|val| serde_json::to_string(&val).ok())
+                .unwrap_or(raw);
+            Some(ToolCallDelta {
+                index: self.next_index,
+                id: None,
+                name: None,
+                arguments: Some(arguments),
+            })
+        } else {
+            None
+        };
+
+        self.buffer.clear();
+        self.state = StreamState::Text;
+        delta
+    }
+
+    fn advance_text(&mut self) -> bool {
+        let start_token = self.config.section_start.clone();
+        match self.buffer.find(start_token.as_str()) {
+            Some(pos) => {
+                self.buffer.drain(..pos + start_token.len());
+                self.state = StreamState::InSection;
+                true
+            }
+            None => {
+                self.retain_only_marker_prefix(&[start_token.as_str()]);
+                false
+            }
+        }
+    }
+
+    fn advance_in_section(&mut self) -> bool {
+        let call_start = self.config.call_start.clone();
+        let section_end = self.config.section_end.clone();
+
+        let call_pos = self.buffer.find(call_start.as_str());
+        let end_pos = self.buffer.find(section_end.as_str());
+
+        match (call_pos, end_pos) {
+            (Some(cp), Some(ep)) if cp < ep => {
+                self.buffer.drain(..cp + call_start.len());
+                self.state = StreamState::InCallId;
+                true
+            }
+            (Some(cp), None) => {
+                self.buffer.drain(..cp + call_start.len());
+                self.state = StreamState::InCallId;
+                true
+            }
+            (_, Some(ep)) => {
+                self.buffer.drain(..ep + section_end.len());
+                self.state = StreamState::Text;
+                true
+            }
+            (None, None) => {
+                self.retain_only_marker_prefix(&[call_start.as_str(), section_end.as_str()]);
+                false
+            }
+        }
+    }
+
+    fn advance_in_call_id(&mut self, deltas: &mut Vec<ToolCallDelta>) -> bool {
+        let argument_begin = self.config.argument_begin.clone();
+        match self.buffer.find(argument_begin.as_str()) {
+            Some(pos) => {
+                let function_id = self.buffer[..pos].trim().to_string();
+                self.buffer.drain(..pos + argument_begin.len());
+
+                let name = match get_id_regex().captures(&function_id) {
+                    Some(cap) => cap
+                        .name("name")
+                        .map(|m| m.as_str().to_string())
+                        .unwrap_or_else(|| function_id.clone()),
+                    None => function_id.clone(),
+                };
+
+                deltas.push(ToolCallDelta {
+                    index: self.next_index,
+                    id: Some(function_id),
+                    name: Some(name),
+                    arguments: None,
+                });
+
+                self.state = StreamState::InArgs;
+                true
+            }
+            // Keep the partial id buffered until `argument_begin` arrives in a later chunk.
+            None => false,
+        }
+    }
+
+    fn advance_in_args(&mut self, deltas: &mut Vec<ToolCallDelta>) -> bool {
+        let call_end = self.config.call_end.clone();
+
+        // Scan the whole retained buffer from scratch every call, tracking JSON
+        // string/escape/brace state, so we know which `{`/`}` belong to the argument object
+        // rather than string literals. Nothing is ever drained from `self.buffer` before a
+        // balanced object is found (see the `None` arm below), so it always holds the
+        // complete, from-the-start, not-yet-emitted argument bytes — state can't be carried
+        // between calls without double-counting bytes that were already scanned.
+        let mut emit_upto = 0;
+        let mut brace_depth = 0i32;
+        let mut in_string = false;
+        let mut escaped = false;
+        let mut seen_open_brace = false;
+        for (idx, ch) in self.buffer.char_indices() {
+            if escaped {
+                escaped = false;
+                continue;
+            }
+            match ch {
+                '\\' if in_string => escaped = true,
+                '"' => in_string = !in_string,
+                '{' if !in_string => {
+                    brace_depth += 1;
+                    seen_open_brace = true;
+                }
+                '}' if !in_string => {
+                    brace_depth -= 1;
+                    if seen_open_brace && brace_depth == 0 {
+                        emit_upto = idx + ch.len_utf8();
+                        break;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        if emit_upto > 0 {
+            // Arguments are balanced; emit them and switch to watching for `call_end` rather
+            // than continuing the brace scan, so a second call's `{`/`}` in the same section
+            // can never be mistaken for a continuation of this one.
+            let arguments: String = self.buffer.drain(..emit_upto).collect();
+            deltas.push(ToolCallDelta {
+                index: self.next_index,
+                id: None,
+                name: None,
+                arguments: Some(arguments),
+            });
+            self.state = StreamState::AwaitingCallEnd;
+            return true;
+        }
+
+        match self.buffer.find(call_end.as_str()) {
+            Some(pos) => {
+                // No balanced object was found by the brace scan above, so the model emitted
+                // non-JSON (or empty) arguments; fall back to the raw text up to `call_end`.
+                if pos > 0 {
+                    let arguments: String = self.buffer.drain(..pos).collect();
+                    deltas.push(ToolCallDelta {
+                        index: self.next_index,
+                        id: None,
+                        name: None,
+                        arguments: Some(arguments),
+                    });
+                }
+                self.buffer.drain(..call_end.len());
+                self.next_index += 1;
+                self.state = StreamState::InSection;
+                true
+            }
+            // Arguments are not yet balanced and `call_end` hasn't appeared either: hold
+            // everything back rather than guessing at a safe prefix to emit early, so `finish`
+            // can still flush the true unterminated remainder if the stream ends here.
+            None => false,
+        }
+    }
+
+    fn advance_awaiting_call_end(&mut self) -> bool {
+        let call_end = self.config.call_end.clone();
+        match self.buffer.find(call_end.as_str()) {
+            Some(pos) => {
+                // Anything between the balanced arguments and `call_end` (e.g. trailing
+                // whitespace) is discarded; it was already accounted for by `advance_in_args`.
+                self.buffer.drain(..pos + call_end.len());
+                self.next_index += 1;
+                self.state = StreamState::InSection;
+                true
+            }
+            None => {
+                self.retain_only_marker_prefix(&[call_end.as_str()]);
+                false
+            }
+        }
+    }
+
+    /// Drop everything in the buffer except a trailing prefix of one of `markers`, so a
+    /// token fragment split across chunk boundaries is completed by the next `push`.
+    fn retain_only_marker_prefix(&mut self, markers: &[&str]) {
+        let cut = self.marker_prefix_cut(markers);
+        self.buffer.drain(..cut);
+    }
+
+    /// Returns the byte offset of the longest suffix of `self.buffer` that is a strict,
+    /// non-empty prefix of one of `markers` (and therefore a valid `str` char boundary).
+    fn marker_prefix_cut(&self, markers: &[&str]) -> usize {
+        for (idx, _) in self.buffer.char_indices() {
+            let suffix = &self.buffer[idx..];
+            if markers
+                .iter()
+                .any(|m| !suffix.is_empty() && m.len() > suffix.len() && m.starts_with(suffix))
+            {
+                return idx;
+            }
+        }
+        self.buffer.len()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -417,4 +935,189 @@ mod tests {
         assert_eq!(args["items"], serde_json::json!([1, 2, 3]));
         assert_eq!(args["config"]["nested"], true);
     }
+
+    #[test]
+    fn test_stream_parser_single_call_one_shot() {
+        let mut parser = KimiK25StreamParser::new(default_config());
+        let input = r#"<|tool_calls_section_begin|><|tool_call_begin|>functions.get_weather:0<|tool_call_argument_begin|>{"location":"NYC"}<|tool_call_end|><|tool_calls_section_end|>"#;
+
+        let deltas = parser.push(input);
+        assert_eq!(deltas.len(), 2);
+        assert_eq!(deltas[0].name.as_deref(), Some("get_weather"));
+        assert_eq!(deltas[0].arguments, None);
+        assert_eq!(deltas[1].name, None);
+        assert_eq!(deltas[1].arguments.as_deref(), Some(r#"{"location":"NYC"}"#));
+        assert!(parser.finish().is_none());
+    }
+
+    #[test]
+    fn test_stream_parser_token_by_token() {
+        let mut parser = KimiK25StreamParser::new(default_config());
+        let mut name = None;
+        let mut arguments = String::new();
+
+        for token in [
+            "<|tool_calls_section_begin|>",
+            "<|tool_call_be",
+            "gin|>functions.get_weather:0<|tool_call_argument_begin|>",
+            r#"{"location":"#,
+            r#""NYC"}"#,
+            "<|tool_call_end|><|tool_calls_section_end|>",
+        ] {
+            for delta in parser.push(token) {
+                if let Some(n) = delta.name {
+                    name = Some(n);
+                }
+                if let Some(a) = delta.arguments {
+                    arguments.push_str(&a);
+                }
+            }
+        }
+
+        assert_eq!(name.as_deref(), Some("get_weather"));
+        assert_eq!(arguments, r#"{"location":"NYC"}"#);
+        assert!(parser.finish().is_none());
+    }
+
+    #[test]
+    fn test_stream_parser_emits_arguments_before_call_end_seen() {
+        // Arguments spanning multiple `push` calls must balance and emit as soon as the closing
+        // brace arrives, without needing `<|tool_call_end|>` in the same or a later chunk.
+        let mut parser = KimiK25StreamParser::new(default_config());
+        parser.push(r#"<|tool_calls_section_begin|><|tool_call_begin|>functions.get_weather:0<|tool_call_argument_begin|>{"a":"#);
+
+        let deltas = parser.push("1}");
+        let arguments: String = deltas
+            .iter()
+            .filter_map(|d| d.arguments.as_deref())
+            .collect();
+        assert_eq!(
+            arguments, r#"{"a":1}"#,
+            "balanced object must be emitted before call_end arrives"
+        );
+
+        // call_end arrives afterwards with nothing left to flush for this call.
+        let deltas = parser.push("<|tool_call_end|><|tool_calls_section_end|>");
+        assert!(deltas.iter().all(|d| d.arguments.is_none()));
+    }
+
+    #[test]
+    fn test_stream_parser_empty_arguments() {
+        let mut parser = KimiK25StreamParser::new(default_config());
+        let input = r#"<|tool_calls_section_begin|><|tool_call_begin|>functions.get_current_time:0<|tool_call_argument_begin|>{}<|tool_call_end|><|tool_calls_section_end|>"#;
+
+        let deltas = parser.push(input);
+        assert_eq!(deltas[0].name.as_deref(), Some("get_current_time"));
+        let args_delta = deltas.iter().find_map(|d| d.arguments.as_deref());
+        assert_eq!(args_delta, Some("{}"));
+    }
+
+    #[test]
+    fn test_stream_parser_multiple_calls_in_one_section() {
+        let mut parser = KimiK25StreamParser::new(default_config());
+        let input = r#"<|tool_calls_section_begin|><|tool_call_begin|>functions.get_weather:0<|tool_call_argument_begin|>{"location":"NYC"}<|tool_call_end|><|tool_call_begin|>functions.get_time:1<|tool_call_argument_begin|>{"timezone":"EST"}<|tool_call_end|><|tool_calls_section_end|>"#;
+
+        let deltas = parser.push(input);
+        let indices: Vec<usize> = deltas.iter().map(|d| d.index).collect();
+        assert!(indices.contains(&0));
+        assert!(indices.contains(&1));
+
+        let names: Vec<&str> = deltas.iter().filter_map(|d| d.name.as_deref()).collect();
+        assert_eq!(names, vec!["get_weather", "get_time"]);
+    }
+
+    #[test]
+    fn test_stream_parser_split_utf8_boundary() {
+        let mut parser = KimiK25StreamParser::new(default_config());
+        let full = r#"<|tool_calls_section_begin|><|tool_call_begin|>functions.search:0<|tool_call_argument_begin|>{"query":"café "#.to_string();
+        // Split the chunk just before the multi-byte "é" (the last 3 bytes are "é" plus a
+        // space); `push` only ever receives valid `&str` chunks, so boundaries always fall on
+        // whole characters.
+        let (first, second) = full.split_at(full.len() - 3);
+        parser.push(first);
+        parser.push(second);
+        let deltas = parser.push(r#"ok"}<|tool_call_end|><|tool_calls_section_end|>"#);
+
+        let arguments: String = std::iter::once(&deltas)
+            .flatten()
+            .filter_map(|d| d.arguments.as_deref())
+            .collect();
+        assert!(arguments.contains("café"));
+    }
+
+    #[test]
+    fn test_stream_parser_finish_flushes_unterminated_call() {
+        // The stream cuts off mid-string with no `call_end`; `finish` closes the dangling
+        // string and object via the same repair pass `repair_json_arguments` uses, rather than
+        // returning the unparseable raw fragment.
+        let mut parser = KimiK25StreamParser::new(default_config());
+        parser.push(r#"<|tool_calls_section_begin|><|tool_call_begin|>functions.get_weather:0<|tool_call_argument_begin|>{"location":"NY"#);
+
+        let flushed = parser.finish().expect("pending arguments should flush");
+        assert_eq!(flushed.arguments.as_deref(), Some(r#"{"location":"NY"}"#));
+    }
+
+    #[test]
+    fn test_stream_parser_finish_falls_back_to_raw_when_unrepairable() {
+        // Truncated input that isn't JSON at all (no braces to close, nothing salvageable)
+        // keeps the raw fragment rather than losing it.
+        let mut parser = KimiK25StreamParser::new(default_config());
+        parser.push(r#"<|tool_calls_section_begin|><|tool_call_begin|>functions.get_weather:0<|tool_call_argument_begin|>not-json-at-all"#);
+
+        let flushed = parser.finish().expect("pending arguments should flush");
+        assert_eq!(flushed.arguments.as_deref(), Some("not-json-at-all"));
+    }
+
+    #[test]
+    fn test_repair_trailing_comma() {
+        let repaired = repair_json_arguments(r#"{"location":"NYC","unit":"c",}"#).unwrap();
+        assert_eq!(repaired["location"], "NYC");
+        assert_eq!(repaired["unit"], "c");
+    }
+
+    #[test]
+    fn test_repair_python_literals() {
+        let repaired =
+            repair_json_arguments(r#"{"enabled":True,"disabled":False,"note":None}"#).unwrap();
+        assert_eq!(repaired["enabled"], true);
+        assert_eq!(repaired["disabled"], false);
+        assert!(repaired["note"].is_null());
+    }
+
+    #[test]
+    fn test_repair_single_quotes() {
+        let repaired = repair_json_arguments(r#"{'location': 'NYC', 'unit': 'celsius'}"#).unwrap();
+        assert_eq!(repaired["location"], "NYC");
+        assert_eq!(repaired["unit"], "celsius");
+    }
+
+    #[test]
+    fn test_repair_truncated_mid_object() {
+        let repaired = repair_json_arguments(r#"{"location":"NYC","unit":"c"#).unwrap();
+        assert_eq!(repaired["location"], "NYC");
+        assert_eq!(repaired["unit"], "c");
+    }
+
+    #[test]
+    fn test_repair_truncated_nested_structures() {
+        let repaired = repair_json_arguments(r#"{"items":[1,2,3],"config":{"nested":true"#).unwrap();
+        assert_eq!(repaired["items"], serde_json::json!([1, 2, 3]));
+        assert_eq!(repaired["config"]["nested"], true);
+    }
+
+    #[test]
+    fn test_repair_gives_up_on_non_object() {
+        assert!(repair_json_arguments("not json at all").is_none());
+        assert!(repair_json_arguments("[1, 2, 3]").is_none());
+    }
+
+    #[test]
+    fn test_parse_section_block_falls_back_to_repaired_arguments() {
+        let input = r#"<|tool_calls_section_begin|><|tool_call_begin|>functions.get_weather:0<|tool_call_argument_begin|>{"location":"NYC","unit":"c",}<|tool_call_end|><|tool_calls_section_end|>"#;
+        let (calls, _) = try_tool_call_parse_kimi_k25(input, &default_config(), None).unwrap();
+        assert_eq!(calls.len(), 1);
+        let args: serde_json::Value = serde_json::from_str(&calls[0].function.arguments).unwrap();
+        assert_eq!(args["location"], "NYC");
+        assert_eq!(args["unit"], "c");
+    }
 }
@@ -3,12 +3,17 @@
 
 mod kimi_k25_parser;
 mod parser;
+mod registry;
+mod stream;
 
 pub use super::response;
 pub use kimi_k25_parser::{
-    detect_tool_call_start_kimi_k25, find_tool_call_end_position_kimi_k25,
+    KimiK25StreamParser, detect_tool_call_start_kimi_k25, find_tool_call_end_position_kimi_k25,
     try_tool_call_parse_kimi_k25,
 };
 pub use parser::{
-    detect_tool_call_start_xml, find_tool_call_end_position_xml, try_tool_call_parse_xml,
+    XmlStreamParser, detect_tool_call_start_xml, find_tool_call_end_position_xml,
+    try_tool_call_parse_xml,
 };
+pub use registry::{KimiK25Parser, ParserRegistry, ToolCallParser, XmlParser};
+pub use stream::ToolCallDelta;
@@ -0,0 +1,406 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025-2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::super::ToolDefinition;
+use super::response::{CalledFunction, ToolCallResponse, ToolCallType};
+use super::stream::ToolCallDelta;
+
+const SECTION_START: &str = "<tool_calls>";
+const SECTION_END: &str = "</tool_calls>";
+const CALL_START: &str = "<tool_call>";
+const CALL_END: &str = "</tool_call>";
+const NAME_START: &str = "<name>";
+const NAME_END: &str = "</name>";
+const ARGUMENTS_START: &str = "<arguments>";
+const ARGUMENTS_END: &str = "</arguments>";
+
+/// Check if a chunk contains the start of an XML-style tool call.
+/// Detects `<tool_calls>` or a partial match for streaming.
+pub fn detect_tool_call_start_xml(chunk: &str) -> bool {
+    if chunk.contains(SECTION_START) {
+        return true;
+    }
+
+    for i in 1..SECTION_START.len() {
+        if chunk.ends_with(&SECTION_START[..i]) {
+            return true;
+        }
+    }
+
+    false
+}
+
+/// Find the end position of an XML tool calls section.
+/// Returns the position after `</tool_calls>` or the length of the chunk if not found.
+pub fn find_tool_call_end_position_xml(chunk: &str) -> usize {
+    match chunk.find(SECTION_END) {
+        Some(pos) => pos + SECTION_END.len(),
+        None => chunk.len(),
+    }
+}
+
+/// Try to parse XML-style tool calls from a message.
+///
+/// Format:
+/// ```text
+/// <tool_calls>
+/// <tool_call><name>get_weather</name><arguments>{"location":"NYC"}</arguments></tool_call>
+/// </tool_calls>
+/// ```
+///
+/// Returns (parsed_tool_calls, normal_text_content)
+pub fn try_tool_call_parse_xml(
+    message: &str,
+    tools: Option<&[ToolDefinition]>,
+) -> anyhow::Result<(Vec<ToolCallResponse>, Option<String>)> {
+    let mut normal_parts = Vec::new();
+    let mut calls = Vec::new();
+    let mut cursor = 0;
+
+    while cursor < message.len() {
+        if let Some(start_pos) = message[cursor..].find(SECTION_START) {
+            let abs_start = cursor + start_pos;
+            normal_parts.push(&message[cursor..abs_start]);
+
+            if let Some(end_pos) = message[abs_start..].find(SECTION_END) {
+                let abs_end = abs_start + end_pos + SECTION_END.len();
+                let block = &message[abs_start..abs_end];
+                calls.extend(parse_section_block(block, tools));
+                cursor = abs_end;
+            } else {
+                normal_parts.push(&message[abs_start..]);
+                break;
+            }
+        } else {
+            normal_parts.push(&message[cursor..]);
+            break;
+        }
+    }
+
+    let normal_text = normal_parts.join("").trim().to_string();
+    let normal_content = if normal_text.is_empty() {
+        Some(String::new())
+    } else {
+        Some(normal_text)
+    };
+
+    Ok((calls, normal_content))
+}
+
+fn parse_section_block(block: &str, tools: Option<&[ToolDefinition]>) -> Vec<ToolCallResponse> {
+    let mut results = Vec::new();
+    let mut cursor = 0;
+    let mut index = 0usize;
+
+    while let Some(start_pos) = block[cursor..].find(CALL_START) {
+        let call_begin = cursor + start_pos + CALL_START.len();
+        let Some(end_pos) = block[call_begin..].find(CALL_END) else {
+            break;
+        };
+        let call_body = &block[call_begin..call_begin + end_pos];
+        cursor = call_begin + end_pos + CALL_END.len();
+
+        let Some((name, arguments_raw)) = extract_name_and_arguments(call_body) else {
+            continue;
+        };
+
+        if let Some(tools) = tools {
+            if !tools.iter().any(|t| t.name == name) {
+                tracing::warn!("Tool '{}' is not defined in the tools list.", name);
+            }
+        }
+
+        let arguments = match serde_json::from_str::<serde_json::Value>(&arguments_raw) {
+            Ok(val) => val.to_string(),
+            Err(e) => {
+                tracing::warn!(
+                    "Failed to parse JSON arguments for tool '{}': {}. Using raw string.",
+                    name,
+                    e,
+                );
+                arguments_raw
+            }
+        };
+
+        results.push(ToolCallResponse {
+            id: format!("call-{index}"),
+            tp: ToolCallType::Function,
+            function: CalledFunction { name, arguments },
+        });
+        index += 1;
+    }
+
+    results
+}
+
+fn extract_name_and_arguments(call_body: &str) -> Option<(String, String)> {
+    let name_start = call_body.find(NAME_START)? + NAME_START.len();
+    let name_end = call_body[name_start..].find(NAME_END)? + name_start;
+    let name = call_body[name_start..name_end].trim().to_string();
+
+    let args_start = call_body.find(ARGUMENTS_START)? + ARGUMENTS_START.len();
+    let args_end = call_body[args_start..].find(ARGUMENTS_END)? + args_start;
+    let arguments = call_body[args_start..args_end].trim().to_string();
+
+    Some((name, arguments))
+}
+
+/// Internal state of [`XmlStreamParser`] as it scans the incoming byte stream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StreamState {
+    /// Outside any tool calls section; waiting for `<tool_calls>`.
+    Text,
+    /// Inside a section, waiting for the next `<tool_call>` (or `</tool_calls>`).
+    InSection,
+    /// Seen `<tool_call>`, waiting for `<name>...</name>`.
+    InName,
+    /// Seen `</name>`, waiting for `<arguments>`.
+    AwaitingArguments,
+    /// Seen `<arguments>`, streaming JSON argument bytes up to `</arguments>`.
+    InArguments,
+    /// Arguments closed, waiting for `</tool_call>`.
+    AwaitingCallEnd,
+}
+
+/// Stateful, incremental counterpart to [`try_tool_call_parse_xml`].
+///
+/// Mirrors [`super::kimi_k25_parser::KimiK25StreamParser`]'s carry-over buffering: special
+/// tokens split across chunk boundaries are held back rather than emitted or dropped.
+#[derive(Debug)]
+pub struct XmlStreamParser {
+    buffer: String,
+    state: StreamState,
+    next_index: usize,
+}
+
+impl Default for XmlStreamParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl XmlStreamParser {
+    pub fn new() -> Self {
+        Self {
+            buffer: String::new(),
+            state: StreamState::Text,
+            next_index: 0,
+        }
+    }
+
+    pub fn push(&mut self, chunk: &str) -> Vec<ToolCallDelta> {
+        self.buffer.push_str(chunk);
+
+        let mut deltas = Vec::new();
+        while self.advance(&mut deltas) {}
+        deltas
+    }
+
+    pub fn finish(&mut self) -> Option<ToolCallDelta> {
+        let delta = if self.state == StreamState::InArguments && !self.buffer.is_empty() {
+            Some(ToolCallDelta {
+                index: self.next_index,
+                id: None,
+                name: None,
+                arguments: Some(std::mem::take(&mut self.buffer)),
+            })
+        } else {
+            None
+        };
+
+        self.buffer.clear();
+        self.state = StreamState::Text;
+        delta
+    }
+
+    fn advance(&mut self, deltas: &mut Vec<ToolCallDelta>) -> bool {
+        match self.state {
+            StreamState::Text => self.consume_marker(SECTION_START, StreamState::InSection),
+            StreamState::InSection => {
+                let call_pos = self.buffer.find(CALL_START);
+                let end_pos = self.buffer.find(SECTION_END);
+                match (call_pos, end_pos) {
+                    (Some(cp), Some(ep)) if cp < ep => {
+                        self.buffer.drain(..cp + CALL_START.len());
+                        self.state = StreamState::InName;
+                        true
+                    }
+                    (Some(cp), None) => {
+                        self.buffer.drain(..cp + CALL_START.len());
+                        self.state = StreamState::InName;
+                        true
+                    }
+                    (_, Some(ep)) => {
+                        self.buffer.drain(..ep + SECTION_END.len());
+                        self.state = StreamState::Text;
+                        true
+                    }
+                    (None, None) => {
+                        self.retain_marker_prefix(&[CALL_START, SECTION_END]);
+                        false
+                    }
+                }
+            }
+            StreamState::InName => {
+                let Some(start) = self.buffer.find(NAME_START) else {
+                    self.retain_marker_prefix(&[NAME_START]);
+                    return false;
+                };
+                let from = start + NAME_START.len();
+                let Some(end) = self.buffer[from..].find(NAME_END) else {
+                    return false;
+                };
+                let name = self.buffer[from..from + end].trim().to_string();
+                self.buffer.drain(..from + end + NAME_END.len());
+                deltas.push(ToolCallDelta {
+                    index: self.next_index,
+                    id: None,
+                    name: Some(name),
+                    arguments: None,
+                });
+                self.state = StreamState::AwaitingArguments;
+                true
+            }
+            StreamState::AwaitingArguments => {
+                self.consume_marker(ARGUMENTS_START, StreamState::InArguments)
+            }
+            StreamState::InArguments => match self.buffer.find(ARGUMENTS_END) {
+                Some(pos) => {
+                    if pos > 0 {
+                        let arguments: String = self.buffer.drain(..pos).collect();
+                        deltas.push(ToolCallDelta {
+                            index: self.next_index,
+                            id: None,
+                            name: None,
+                            arguments: Some(arguments),
+                        });
+                    }
+                    self.buffer.drain(..ARGUMENTS_END.len());
+                    self.state = StreamState::AwaitingCallEnd;
+                    true
+                }
+                // `</arguments>` hasn't appeared yet: hold everything back rather than
+                // guessing at a safe prefix to emit early, so `finish` can still flush the
+                // true unterminated remainder if the stream ends here.
+                None => false,
+            },
+            StreamState::AwaitingCallEnd => {
+                if self.consume_marker(CALL_END, StreamState::InSection) {
+                    self.next_index += 1;
+                    true
+                } else {
+                    false
+                }
+            }
+        }
+    }
+
+    fn consume_marker(&mut self, marker: &str, next: StreamState) -> bool {
+        match self.buffer.find(marker) {
+            Some(pos) => {
+                self.buffer.drain(..pos + marker.len());
+                self.state = next;
+                true
+            }
+            None => {
+                self.retain_marker_prefix(&[marker]);
+                false
+            }
+        }
+    }
+
+    fn retain_marker_prefix(&mut self, markers: &[&str]) {
+        let cut = self.marker_prefix_cut(markers);
+        self.buffer.drain(..cut);
+    }
+
+    fn marker_prefix_cut(&self, markers: &[&str]) -> usize {
+        for (idx, _) in self.buffer.char_indices() {
+            let suffix = &self.buffer[idx..];
+            if markers
+                .iter()
+                .any(|m| !suffix.is_empty() && m.len() > suffix.len() && m.starts_with(suffix))
+            {
+                return idx;
+            }
+        }
+        self.buffer.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_tool_call_start() {
+        assert!(detect_tool_call_start_xml("<tool_calls>"));
+        assert!(detect_tool_call_start_xml("text <tool_calls>"));
+        assert!(detect_tool_call_start_xml("<tool_cal"));
+        assert!(!detect_tool_call_start_xml("no tool call here"));
+    }
+
+    #[test]
+    fn test_parse_simple_tool_call() {
+        let input = "<tool_calls><tool_call><name>get_weather</name><arguments>{\"location\":\"NYC\"}</arguments></tool_call></tool_calls>";
+        let (calls, normal) = try_tool_call_parse_xml(input, None).unwrap();
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].function.name, "get_weather");
+        assert_eq!(normal, Some(String::new()));
+    }
+
+    #[test]
+    fn test_parse_multiple_tool_calls() {
+        let input = "<tool_calls><tool_call><name>get_weather</name><arguments>{\"location\":\"NYC\"}</arguments></tool_call><tool_call><name>get_time</name><arguments>{\"timezone\":\"EST\"}</arguments></tool_call></tool_calls>";
+        let (calls, _) = try_tool_call_parse_xml(input, None).unwrap();
+        assert_eq!(calls.len(), 2);
+        assert_eq!(calls[0].function.name, "get_weather");
+        assert_eq!(calls[1].function.name, "get_time");
+    }
+
+    #[test]
+    fn test_parse_no_tool_calls() {
+        let input = "This is just normal text.";
+        let (calls, normal) = try_tool_call_parse_xml(input, None).unwrap();
+        assert_eq!(calls.len(), 0);
+        assert_eq!(normal, Some(input.to_string()));
+    }
+
+    #[test]
+    fn test_stream_parser_single_call_one_shot() {
+        let mut parser = XmlStreamParser::new();
+        let input = "<tool_calls><tool_call><name>get_weather</name><arguments>{\"location\":\"NYC\"}</arguments></tool_call></tool_calls>";
+        let deltas = parser.push(input);
+
+        assert_eq!(deltas[0].name.as_deref(), Some("get_weather"));
+        let arguments: String = deltas.iter().filter_map(|d| d.arguments.clone()).collect();
+        assert_eq!(arguments, r#"{"location":"NYC"}"#);
+        assert!(parser.finish().is_none());
+    }
+
+    #[test]
+    fn test_stream_parser_split_marker_across_chunks() {
+        let mut parser = XmlStreamParser::new();
+        let mut deltas = Vec::new();
+        for token in [
+            "<tool_calls><tool_c",
+            "all><name>search</name><argum",
+            "ents>{}</arguments></tool_call></tool_calls>",
+        ] {
+            deltas.extend(parser.push(token));
+        }
+
+        assert_eq!(deltas[0].name.as_deref(), Some("search"));
+        let arguments: String = deltas.iter().filter_map(|d| d.arguments.clone()).collect();
+        assert_eq!(arguments, "{}");
+    }
+
+    #[test]
+    fn test_stream_parser_finish_flushes_unterminated_call() {
+        let mut parser = XmlStreamParser::new();
+        parser.push("<tool_calls><tool_call><name>get_weather</name><arguments>{\"location\":\"NY");
+
+        let flushed = parser.finish().expect("pending arguments should flush");
+        assert_eq!(flushed.arguments.as_deref(), Some(r#"{"location":"NY"#));
+    }
+}
@@ -0,0 +1,156 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025-2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use super::super::ToolDefinition;
+use super::super::config::KimiK25ParserConfig;
+use super::response::ToolCallResponse;
+use super::{
+    detect_tool_call_start_kimi_k25, detect_tool_call_start_xml,
+    find_tool_call_end_position_kimi_k25, find_tool_call_end_position_xml,
+    try_tool_call_parse_kimi_k25, try_tool_call_parse_xml,
+};
+
+/// A model/format-specific tool-call dialect.
+///
+/// Implementations wrap the free functions for a given dialect (Kimi K2.5, XML, ...) so a
+/// consumer can resolve a parser once by name and call through this trait, instead of branching
+/// on model type at every call site. Adding a new dialect (Llama-3.1 JSON, Hermes/Qwen,
+/// Mistral `[TOOL_CALLS]`, ...) means adding a new impl, not a new exported function.
+pub trait ToolCallParser: Send + Sync {
+    /// Whether `chunk` contains (or could be the start of) this dialect's tool-call marker.
+    fn detect_start(&self, chunk: &str) -> bool;
+
+    /// The position just past this dialect's closing marker, or `chunk.len()` if not found.
+    fn find_end_position(&self, chunk: &str) -> usize;
+
+    /// Parse a complete message, returning any tool calls and the remaining normal text.
+    fn parse(
+        &self,
+        message: &str,
+        tools: Option<&[ToolDefinition]>,
+    ) -> anyhow::Result<(Vec<ToolCallResponse>, Option<String>)>;
+}
+
+/// The Kimi K2.5 pipe-token dialect (`<|tool_calls_section_begin|>`, ...).
+#[derive(Debug, Clone, Default)]
+pub struct KimiK25Parser {
+    config: KimiK25ParserConfig,
+}
+
+impl KimiK25Parser {
+    pub fn new(config: KimiK25ParserConfig) -> Self {
+        Self { config }
+    }
+}
+
+impl ToolCallParser for KimiK25Parser {
+    fn detect_start(&self, chunk: &str) -> bool {
+        detect_tool_call_start_kimi_k25(chunk, &self.config)
+    }
+
+    fn find_end_position(&self, chunk: &str) -> usize {
+        find_tool_call_end_position_kimi_k25(chunk, &self.config)
+    }
+
+    fn parse(
+        &self,
+        message: &str,
+        tools: Option<&[ToolDefinition]>,
+    ) -> anyhow::Result<(Vec<ToolCallResponse>, Option<String>)> {
+        try_tool_call_parse_kimi_k25(message, &self.config, tools)
+    }
+}
+
+/// The generic `<tool_calls>`/`<tool_call>` XML dialect.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct XmlParser;
+
+impl ToolCallParser for XmlParser {
+    fn detect_start(&self, chunk: &str) -> bool {
+        detect_tool_call_start_xml(chunk)
+    }
+
+    fn find_end_position(&self, chunk: &str) -> usize {
+        find_tool_call_end_position_xml(chunk)
+    }
+
+    fn parse(
+        &self,
+        message: &str,
+        tools: Option<&[ToolDefinition]>,
+    ) -> anyhow::Result<(Vec<ToolCallResponse>, Option<String>)> {
+        try_tool_call_parse_xml(message, tools)
+    }
+}
+
+/// Maps a model/format identifier (e.g. `"kimi_k25"`, `"xml"`) to its [`ToolCallParser`].
+///
+/// Consumers resolve a parser once by name and call through the trait, rather than matching on
+/// model type at every call site. New dialects register themselves here instead of exporting
+/// new free functions.
+pub struct ParserRegistry {
+    parsers: HashMap<String, Box<dyn ToolCallParser>>,
+}
+
+impl ParserRegistry {
+    pub fn new() -> Self {
+        Self {
+            parsers: HashMap::new(),
+        }
+    }
+
+    /// A registry pre-populated with the dialects this crate ships.
+    pub fn with_defaults() -> Self {
+        let mut registry = Self::new();
+        registry.register("kimi_k25", Box::new(KimiK25Parser::default()));
+        registry.register("xml", Box::new(XmlParser));
+        registry
+    }
+
+    pub fn register(&mut self, name: &str, parser: Box<dyn ToolCallParser>) {
+        self.parsers.insert(name.to_string(), parser);
+    }
+
+    pub fn get(&self, name: &str) -> Option<&dyn ToolCallParser> {
+        self.parsers.get(name).map(|p| p.as_ref())
+    }
+}
+
+impl Default for ParserRegistry {
+    fn default() -> Self {
+        Self::with_defaults()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry_resolves_known_dialects() {
+        let registry = ParserRegistry::with_defaults();
+        assert!(registry.get("kimi_k25").is_some());
+        assert!(registry.get("xml").is_some());
+        assert!(registry.get("unknown").is_none());
+    }
+
+    #[test]
+    fn test_registry_parses_through_trait() {
+        let registry = ParserRegistry::with_defaults();
+        let parser = registry.get("xml").unwrap();
+        let input = "<tool_calls><tool_call><name>get_weather</name><arguments>{\"location\":\"NYC\"}</arguments></tool_call></tool_calls>";
+        let (calls, _) = parser.parse(input, None).unwrap();
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].function.name, "get_weather");
+    }
+
+    #[test]
+    fn test_registry_can_register_new_dialect() {
+        let mut registry = ParserRegistry::new();
+        assert!(registry.get("xml").is_none());
+        registry.register("xml", Box::new(XmlParser));
+        assert!(registry.get("xml").is_some());
+    }
+}
@@ -0,0 +1,20 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025-2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+/// An OpenAI-style incremental `tool_calls` delta, as produced by a streaming tool-call parser.
+///
+/// A single tool call is assembled from a sequence of deltas sharing the same `index`: the
+/// first carries `name` (and `id`, for dialects that have one), and subsequent ones carry
+/// `arguments` fragments that concatenate (in order) into the complete JSON arguments string.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ToolCallDelta {
+    /// Position of this tool call among those emitted so far in the stream.
+    pub index: usize,
+    /// The model-native call id (e.g. `functions.get_weather:0`), present on the first delta.
+    /// Always `None` for the XML dialect, which carries no call id.
+    pub id: Option<String>,
+    /// The function name, present on the first delta.
+    pub name: Option<String>,
+    /// An incremental fragment of the `arguments` JSON string.
+    pub arguments: Option<String>,
+}